@@ -0,0 +1,237 @@
+//! Small animation engine for telemetry-driven status indicators.
+//!
+//! Each [`StatusIndicator`] maps a live value onto a set of keyframe
+//! [`ColorStop`]s, lerped in HSV space, with [`InterpolationMode`] choosing
+//! how the interpolation parameter itself is derived each frame.
+
+use chrono::Local;
+// Use egui's Color32 from bevy_egui to avoid version conflicts, matching
+// how `app` pulls it in.
+use bevy_egui::egui::Color32;
+
+use crate::telemetry::DataBuffer;
+
+/// How the interpolation parameter for a stop is derived each frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InterpolationMode {
+    /// Parameter is the normalized source value directly.
+    Solid,
+    /// Parameter follows a sine wave over elapsed time.
+    Pulse { period_secs: f32 },
+    /// Parameter square-waves fully on/off over elapsed time.
+    Blink { period_secs: f32 },
+}
+
+/// A color at a normalized `0.0..=1.0` position along an indicator's range.
+#[derive(Debug, Clone, Copy)]
+pub struct ColorStop {
+    pub position: f32,
+    pub color: Color32,
+}
+
+/// Where an indicator's normalized `0.0..=1.0` value comes from.
+#[derive(Debug, Clone, Copy)]
+pub enum IndicatorSource {
+    /// Battery voltage, normalized between `empty` and `full` volts.
+    Battery { empty: f32, full: f32 },
+    /// RSSI in dBm, normalized between `floor` and `ceiling`.
+    Rssi { floor: f32, ceiling: f32 },
+    /// SNR in dB, normalized between `floor` and `ceiling`.
+    Snr { floor: f32, ceiling: f32 },
+    /// Seconds since the last telemetry sample, normalized against
+    /// `timeout` (1.0 = link considered lost).
+    LinkLoss { timeout: f32 },
+}
+
+impl IndicatorSource {
+    pub fn normalize(&self, buffer: &DataBuffer) -> f32 {
+        match *self {
+            IndicatorSource::Battery { empty, full } => {
+                let voltage = buffer.data.back().map(|d| d.battery_voltage).unwrap_or(empty);
+                ((voltage - empty) / (full - empty).max(f32::EPSILON)).clamp(0.0, 1.0)
+            }
+            IndicatorSource::Rssi { floor, ceiling } => {
+                let rssi = buffer
+                    .received
+                    .back()
+                    .map(|m| m.rssi as f32)
+                    .unwrap_or(floor);
+                ((rssi - floor) / (ceiling - floor).max(f32::EPSILON)).clamp(0.0, 1.0)
+            }
+            IndicatorSource::Snr { floor, ceiling } => {
+                let snr = buffer.received.back().map(|m| m.snr as f32).unwrap_or(floor);
+                ((snr - floor) / (ceiling - floor).max(f32::EPSILON)).clamp(0.0, 1.0)
+            }
+            IndicatorSource::LinkLoss { timeout } => {
+                let since_last = buffer
+                    .data
+                    .back()
+                    .map(|d| (Local::now() - d.time).num_milliseconds() as f32 / 1000.0)
+                    .unwrap_or(timeout);
+                (since_last / timeout.max(f32::EPSILON)).clamp(0.0, 1.0)
+            }
+        }
+    }
+}
+
+/// One telemetry-driven status indicator: a source value mapped through
+/// keyframe color stops, rendered as a small colored dot in the status bar.
+#[derive(Debug, Clone)]
+pub struct StatusIndicator {
+    pub label: String,
+    pub source: IndicatorSource,
+    pub stops: Vec<ColorStop>,
+    pub mode: InterpolationMode,
+}
+
+impl StatusIndicator {
+    /// Compute this frame's color from the normalized source value and
+    /// elapsed time (used by the pulse/blink modes).
+    pub fn color(&self, normalized_value: f32, elapsed_secs: f32) -> Color32 {
+        let value = normalized_value.clamp(0.0, 1.0);
+        let t = match self.mode {
+            InterpolationMode::Solid => value,
+            InterpolationMode::Pulse { period_secs } => {
+                let phase = (elapsed_secs / period_secs.max(0.001)) * std::f32::consts::TAU;
+                value * (0.5 + 0.5 * phase.sin())
+            }
+            InterpolationMode::Blink { period_secs } => {
+                let phase = (elapsed_secs / period_secs.max(0.001)).fract();
+                if phase < 0.5 { value } else { 0.0 }
+            }
+        };
+
+        lerp_stops(&self.stops, t)
+    }
+}
+
+fn lerp_stops(stops: &[ColorStop], t: f32) -> Color32 {
+    let Some(first) = stops.first() else {
+        return Color32::GRAY;
+    };
+    if stops.len() == 1 || t <= first.position {
+        return first.color;
+    }
+
+    let last = stops[stops.len() - 1];
+    if t >= last.position {
+        return last.color;
+    }
+
+    for pair in stops.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        if t >= a.position && t <= b.position {
+            let span = (b.position - a.position).max(f32::EPSILON);
+            return lerp_hsv(a.color, b.color, (t - a.position) / span);
+        }
+    }
+
+    last.color
+}
+
+/// Lerp two colors in HSV space, which avoids the dull, grayed-out midpoints
+/// a plain RGB lerp produces, then convert back to `Color32`.
+fn lerp_hsv(a: Color32, b: Color32, t: f32) -> Color32 {
+    let (ha, sa, va) = rgb_to_hsv(a);
+    let (hb, sb, vb) = rgb_to_hsv(b);
+
+    let mut dh = hb - ha;
+    if dh > 0.5 {
+        dh -= 1.0;
+    } else if dh < -0.5 {
+        dh += 1.0;
+    }
+
+    let h = (ha + dh * t).rem_euclid(1.0);
+    let s = sa + (sb - sa) * t;
+    let v = va + (vb - va) * t;
+    hsv_to_rgb(h, s, v)
+}
+
+fn rgb_to_hsv(color: Color32) -> (f32, f32, f32) {
+    let r = color.r() as f32 / 255.0;
+    let g = color.g() as f32 / 255.0;
+    let b = color.b() as f32 / 255.0;
+
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    let h = if delta.abs() < f32::EPSILON {
+        0.0
+    } else if max == r {
+        ((g - b) / delta).rem_euclid(6.0) / 6.0
+    } else if max == g {
+        ((b - r) / delta + 2.0) / 6.0
+    } else {
+        ((r - g) / delta + 4.0) / 6.0
+    };
+
+    let s = if max.abs() < f32::EPSILON { 0.0 } else { delta / max };
+    (h, s, max)
+}
+
+fn hsv_to_rgb(h: f32, s: f32, v: f32) -> Color32 {
+    let i = (h * 6.0).floor();
+    let f = h * 6.0 - i;
+    let p = v * (1.0 - s);
+    let q = v * (1.0 - f * s);
+    let t = v * (1.0 - (1.0 - f) * s);
+
+    let (r, g, b) = match (i as i64).rem_euclid(6) {
+        0 => (v, t, p),
+        1 => (q, v, p),
+        2 => (p, v, t),
+        3 => (p, q, v),
+        4 => (t, p, v),
+        _ => (v, p, q),
+    };
+
+    Color32::from_rgb((r * 255.0) as u8, (g * 255.0) as u8, (b * 255.0) as u8)
+}
+
+const LOW_COLOR: Color32 = Color32::from_rgb(220, 40, 40);
+const MID_COLOR: Color32 = Color32::from_rgb(230, 180, 30);
+const HIGH_COLOR: Color32 = Color32::from_rgb(40, 200, 80);
+
+fn health_stops() -> Vec<ColorStop> {
+    vec![
+        ColorStop { position: 0.0, color: LOW_COLOR },
+        ColorStop { position: 0.5, color: MID_COLOR },
+        ColorStop { position: 1.0, color: HIGH_COLOR },
+    ]
+}
+
+/// The default status bar: battery level, link RSSI/SNR solidly colored by
+/// health, and a blinking "link lost" indicator once telemetry goes stale.
+pub fn default_indicators() -> Vec<StatusIndicator> {
+    vec![
+        StatusIndicator {
+            label: "Battery".to_string(),
+            source: IndicatorSource::Battery { empty: 10.5, full: 12.6 },
+            stops: health_stops(),
+            mode: InterpolationMode::Solid,
+        },
+        StatusIndicator {
+            label: "RSSI".to_string(),
+            source: IndicatorSource::Rssi { floor: -120.0, ceiling: -50.0 },
+            stops: health_stops(),
+            mode: InterpolationMode::Solid,
+        },
+        StatusIndicator {
+            label: "SNR".to_string(),
+            source: IndicatorSource::Snr { floor: -10.0, ceiling: 15.0 },
+            stops: health_stops(),
+            mode: InterpolationMode::Solid,
+        },
+        StatusIndicator {
+            label: "Link".to_string(),
+            source: IndicatorSource::LinkLoss { timeout: 3.0 },
+            stops: vec![
+                ColorStop { position: 0.0, color: HIGH_COLOR },
+                ColorStop { position: 1.0, color: LOW_COLOR },
+            ],
+            mode: InterpolationMode::Blink { period_secs: 0.5 },
+        },
+    ]
+}
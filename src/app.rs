@@ -7,10 +7,22 @@ use std::sync::{Arc, Mutex, mpsc};
 // Use egui's Color32 from bevy_egui to avoid version conflicts
 use egui::Color32;
 
-use crate::drone_scene::{Drone, DroneOrientation};
+use crate::animation::StatusIndicator;
+use crate::drone_scene::{CameraMode, Drone, DroneOrientation};
+use crate::input::{AXIS_OPTIONS, BUTTON_OPTIONS, GamepadBindings};
+use crate::net;
 use crate::telemetry::{DataBuffer, PidAxis};
 use crate::uart::{self, UartCommand};
-use crate::video::{self, SharedVideoFrame};
+use crate::video::{self, PipewireOutput, SharedVideoFrame, VideoHandle};
+
+/// Which backend a `UartCommand::Send` (from the Send button or a gamepad
+/// binding) is currently routed through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Transport {
+    #[default]
+    Uart,
+    Udp,
+}
 
 #[derive(Resource, Clone)]
 pub struct AppState {
@@ -25,7 +37,20 @@ pub struct AppState {
     pub video_frame: SharedVideoFrame,
     pub video_texture: Option<egui::TextureHandle>,
     pub video_connected: bool,
+    pub video_handle: Option<VideoHandle>,
     pub video_device_path: String,
+    pub gamepad_bindings: GamepadBindings,
+    pub armed: bool,
+    pub gamepad_send_timer: f32,
+    pub transport: Transport,
+    pub remote_addr: String,
+    pub net_connected: bool,
+    pub net_sender: Option<mpsc::Sender<UartCommand>>,
+    pub video_stream_enabled: bool,
+    pub pipewire_output: Option<Arc<PipewireOutput>>,
+    pub camera_mode: CameraMode,
+    pub camera_follow_distance: f32,
+    pub status_indicators: Vec<StatusIndicator>,
 }
 
 // Gilrs is not Sync, so we keep it as a NonSend resource
@@ -48,7 +73,20 @@ impl Default for AppState {
             video_frame: Arc::new(Mutex::new(None)),
             video_texture: None,
             video_connected: false,
+            video_handle: None,
             video_device_path: "/dev/video2".to_string(),
+            gamepad_bindings: GamepadBindings::default(),
+            armed: false,
+            gamepad_send_timer: 0.0,
+            transport: Transport::default(),
+            remote_addr: "192.168.4.1:7000".to_string(),
+            net_connected: false,
+            net_sender: None,
+            video_stream_enabled: false,
+            pipewire_output: None,
+            camera_mode: CameraMode::default(),
+            camera_follow_distance: 6.0,
+            status_indicators: crate::animation::default_indicators(),
         }
     }
 }
@@ -74,7 +112,7 @@ impl AppState {
     }
 
     fn send_data(&self) {
-        if let Some(sender) = &self.uart_sender {
+        if let Some(sender) = self.active_sender() {
             if let Ok(address) = self.send_address.parse::<u16>() {
                 let cmd = UartCommand::Send {
                     address,
@@ -89,14 +127,52 @@ impl AppState {
         }
     }
 
+    fn start_net_thread(&mut self) {
+        if self.net_connected {
+            return;
+        }
+        let remote_addr = self.remote_addr.clone();
+        let data_buffer = Arc::clone(&self.data_buffer);
+        match net::start_net_thread(remote_addr.clone(), data_buffer) {
+            Ok(sender) => {
+                self.net_sender = Some(sender);
+                self.net_connected = true;
+            }
+            Err(e) => {
+                eprintln!("Failed to connect UDP transport to {remote_addr}: {e}");
+            }
+        }
+    }
+
+    /// The command sender for whichever transport is currently selected, used
+    /// by both the Send button and the gamepad input mapping.
+    pub(crate) fn active_sender(&self) -> Option<&mpsc::Sender<UartCommand>> {
+        match self.transport {
+            Transport::Uart => self.uart_sender.as_ref(),
+            Transport::Udp => self.net_sender.as_ref(),
+        }
+    }
+
+    /// Send an `ARM`/`DISARM` command frame, used by both the Controls panel
+    /// checkbox and the gamepad arm/disarm buttons.
+    pub(crate) fn send_arm_command(&self, armed: bool) {
+        if let Some(sender) = self.active_sender() {
+            let data = if armed { "ARM" } else { "DISARM" }.to_string();
+            if let Err(e) = sender.send(UartCommand::Send { address: 0, data }) {
+                eprintln!("Failed to send arm/disarm command: {e}");
+            }
+        }
+    }
+
     fn start_video_thread(&mut self) {
         if self.video_connected {
             return;
         }
         let device_path = self.video_device_path.clone();
         match video::start_video_thread(&device_path) {
-            Ok(frame_buffer) => {
+            Ok((frame_buffer, handle)) => {
                 self.video_frame = frame_buffer;
+                self.video_handle = Some(handle);
                 self.video_connected = true;
                 println!("Video capture started from {}", device_path);
             }
@@ -105,24 +181,63 @@ impl AppState {
             }
         }
     }
+
+    /// Stop the capture thread and tear down any PipeWire output mirroring
+    /// it, giving the Connect button a real disconnected state to return to.
+    fn disconnect_video(&mut self) {
+        if let Some(handle) = self.video_handle.take() {
+            handle.stop();
+        }
+        self.set_video_stream_enabled(false);
+        self.video_connected = false;
+        self.video_texture = None;
+        if let Ok(mut frame) = self.video_frame.lock() {
+            *frame = None;
+        }
+    }
+
+    fn set_video_stream_enabled(&mut self, enabled: bool) {
+        if enabled == self.video_stream_enabled {
+            return;
+        }
+
+        if !enabled {
+            // Dropping the handle tears the PipeWire node down.
+            self.pipewire_output = None;
+            self.video_stream_enabled = false;
+            return;
+        }
+
+        let Some(frame) = self.video_frame.lock().ok().and_then(|guard| guard.clone()) else {
+            eprintln!("Cannot start PipeWire output before a video frame has been captured");
+            return;
+        };
+
+        match PipewireOutput::start(Arc::clone(&self.video_frame), frame.width, frame.height) {
+            Ok(output) => {
+                self.pipewire_output = Some(Arc::new(output));
+                self.video_stream_enabled = true;
+            }
+            Err(e) => eprintln!("Failed to start PipeWire output: {e}"),
+        }
+    }
 }
 
 /// Main UI system that renders all the egui panels
 pub fn ui_system(
     mut contexts: EguiContexts,
     mut state: ResMut<AppState>,
+    time: Res<Time>,
     gamepad: Option<NonSendMut<GamepadState>>,
     mut drone_query: Query<&mut DroneOrientation, With<Drone>>,
 ) {
-    // Handle gamepad events
-    if let Some(mut gamepad) = gamepad {
-        while let Some(gilrs::Event {
-            id, event, time, ..
-        }) = gamepad.gilrs.next_event()
-        {
-            println!("{:?} New event from {}: {:?}", time, id, event);
-        }
-    }
+    // Gamepad sampling and command dispatch happens in
+    // `input::gamepad_input_system`; this system only checks whether a pad is
+    // connected, for the Controls panel below.
+    let gamepad_connected = gamepad
+        .as_ref()
+        .map(|g| g.gilrs.gamepads().next().is_some())
+        .unwrap_or(false);
 
     // Update video texture if new frame is available
     let frame_data_opt = state
@@ -187,20 +302,62 @@ pub fn ui_system(
             ui.label("Video Device:");
             ui.text_edit_singleline(&mut state.video_device_path);
             if ui
-                .button(if state.video_connected {
+                .button(if state.video_connected { "Disconnect" } else { "Connect" })
+                .clicked()
+            {
+                if state.video_connected {
+                    state.disconnect_video();
+                } else {
+                    state.start_video_thread();
+                }
+            }
+
+            let mut stream_enabled = state.video_stream_enabled;
+            if ui
+                .add_enabled(
+                    state.video_connected,
+                    egui::SelectableLabel::new(stream_enabled, "Stream to PipeWire"),
+                )
+                .clicked()
+            {
+                stream_enabled = !stream_enabled;
+                state.set_video_stream_enabled(stream_enabled);
+            }
+
+            ui.separator();
+
+            // Network (UDP) connection
+            ui.label("Remote Addr:");
+            ui.add(egui::TextEdit::singleline(&mut state.remote_addr).desired_width(120.0));
+            if ui
+                .button(if state.net_connected {
                     "Connected ✓"
                 } else {
                     "Connect"
                 })
                 .clicked()
             {
-                if !state.video_connected {
-                    state.start_video_thread();
+                if !state.net_connected {
+                    state.start_net_thread();
                 }
             }
 
             ui.separator();
 
+            // Transport selector for the Send button and gamepad bindings
+            ui.label("Transport:");
+            egui::ComboBox::from_id_salt("transport")
+                .selected_text(match state.transport {
+                    Transport::Uart => "UART",
+                    Transport::Udp => "UDP",
+                })
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut state.transport, Transport::Uart, "UART");
+                    ui.selectable_value(&mut state.transport, Transport::Udp, "UDP");
+                });
+
+            ui.separator();
+
             // Send data
             ui.label("Address:");
             ui.add(egui::TextEdit::singleline(&mut state.send_address).desired_width(40.0));
@@ -213,6 +370,24 @@ pub fn ui_system(
             ui.separator();
             ui.checkbox(&mut state.auto_scroll_logs, "Auto-scroll logs");
         });
+
+        ui.separator();
+
+        // Status bar - animated link/battery health indicators
+        ui.horizontal(|ui| {
+            let buffer = state.data_buffer.lock().unwrap();
+            let elapsed_secs = time.elapsed_seconds();
+            for indicator in &state.status_indicators {
+                let normalized = indicator.source.normalize(&buffer);
+                let color = indicator.color(normalized, elapsed_secs);
+
+                let (rect, _response) =
+                    ui.allocate_exact_size(egui::vec2(12.0, 12.0), egui::Sense::hover());
+                ui.painter().circle_filled(rect.center(), 5.0, color);
+                ui.label(&indicator.label);
+                ui.separator();
+            }
+        });
     });
 
     // Central Panel - Main content
@@ -349,5 +524,89 @@ pub fn ui_system(
                 ui.label("No data received yet");
             }
         });
+
+        ui.add_space(10.0);
+
+        // 3D view camera
+        ui.group(|ui| {
+            ui.horizontal(|ui| {
+                ui.label("Camera:");
+                ui.selectable_value(&mut state.camera_mode, CameraMode::Chase, "Chase");
+                ui.selectable_value(&mut state.camera_mode, CameraMode::Orbit, "Orbit");
+                ui.separator();
+                ui.label("Follow distance");
+                ui.add(egui::Slider::new(&mut state.camera_follow_distance, 2.0..=20.0));
+            });
+        });
+
+        ui.add_space(10.0);
+
+        // Gamepad bindings
+        ui.group(|ui| {
+            ui.horizontal(|ui| {
+                ui.label("Controls");
+                ui.separator();
+                ui.label(if gamepad_connected {
+                    "Gamepad connected ✓"
+                } else {
+                    "No gamepad detected"
+                });
+                ui.separator();
+                if ui.checkbox(&mut state.armed, "Armed").changed() {
+                    state.send_arm_command(state.armed);
+                }
+            });
+
+            egui::CollapsingHeader::new("Stick bindings")
+                .default_open(false)
+                .show(ui, |ui| {
+                    let bindings = &mut state.gamepad_bindings;
+                    axis_binding_row(ui, "Throttle", &mut bindings.throttle);
+                    axis_binding_row(ui, "Yaw", &mut bindings.yaw);
+                    axis_binding_row(ui, "Roll", &mut bindings.roll);
+                    axis_binding_row(ui, "Pitch", &mut bindings.pitch);
+                });
+
+            egui::CollapsingHeader::new("Button bindings")
+                .default_open(false)
+                .show(ui, |ui| {
+                    let bindings = &mut state.gamepad_bindings;
+                    button_binding_row(ui, "Arm", &mut bindings.arm);
+                    button_binding_row(ui, "Disarm", &mut bindings.disarm);
+                    button_binding_row(ui, "Next PID axis", &mut bindings.next_pid_axis);
+                    button_binding_row(ui, "Gain +", &mut bindings.gain_increase);
+                    button_binding_row(ui, "Gain -", &mut bindings.gain_decrease);
+                });
+        });
+    });
+}
+
+fn axis_binding_row(ui: &mut egui::Ui, label: &str, binding: &mut crate::input::AxisBinding) {
+    ui.horizontal(|ui| {
+        ui.label(label);
+        egui::ComboBox::from_id_salt(label)
+            .selected_text(format!("{:?}", binding.axis))
+            .show_ui(ui, |ui| {
+                for axis in AXIS_OPTIONS {
+                    ui.selectable_value(&mut binding.axis, axis, format!("{axis:?}"));
+                }
+            });
+        ui.label("Deadzone");
+        ui.add(egui::Slider::new(&mut binding.deadzone, 0.0..=0.5));
+        ui.label("Expo");
+        ui.add(egui::Slider::new(&mut binding.expo, 0.0..=1.0));
+    });
+}
+
+fn button_binding_row(ui: &mut egui::Ui, label: &str, button: &mut gilrs::Button) {
+    ui.horizontal(|ui| {
+        ui.label(label);
+        egui::ComboBox::from_id_salt(label)
+            .selected_text(format!("{button:?}"))
+            .show_ui(ui, |ui| {
+                for option in BUTTON_OPTIONS {
+                    ui.selectable_value(button, option, format!("{option:?}"));
+                }
+            });
     });
 }
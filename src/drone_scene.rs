@@ -0,0 +1,162 @@
+//! The 3D Bevy scene: the drone entity, its orientation component updated
+//! from telemetry in `app::ui_system`, and the camera that follows it.
+
+use bevy::prelude::*;
+
+use crate::app::AppState;
+
+/// Marker component for the drone entity in the 3D scene.
+#[derive(Component)]
+pub struct Drone;
+
+/// Attitude driving the drone's 3D transform, updated each frame from the
+/// latest telemetry sample.
+#[derive(Component, Default, Clone, Copy)]
+pub struct DroneOrientation {
+    pub roll: f32,
+    pub pitch: f32,
+    pub yaw: f32,
+}
+
+impl DroneOrientation {
+    pub fn rotation(&self) -> Quat {
+        Quat::from_euler(
+            EulerRot::YXZ,
+            self.yaw.to_radians(),
+            self.pitch.to_radians(),
+            self.roll.to_radians(),
+        )
+    }
+
+    /// World-space "up" vector with roll/pitch/yaw applied, used to bank the
+    /// chase camera along with the drone.
+    pub fn up(&self) -> Vec3 {
+        self.rotation() * Vec3::Y
+    }
+}
+
+/// Which follow behavior the 3D view camera is using, selectable from the
+/// UI and stored on `AppState`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CameraMode {
+    /// Positioned behind-and-above the drone, banking with its roll.
+    #[default]
+    Chase,
+    /// Fixed-distance orbit the user can rotate by dragging with the mouse.
+    Orbit,
+}
+
+/// Marker for the 3D view camera plus its orbit-drag state; the active mode
+/// and follow distance live on `AppState` since the UI controls them.
+#[derive(Component, Default)]
+pub struct FollowCamera {
+    pub orbit_yaw: f32,
+    pub orbit_pitch: f32,
+}
+
+/// How quickly the camera transform interpolates toward its target each
+/// frame; higher is snappier, lower is smoother/laggier.
+const FOLLOW_LERP_SPEED: f32 = 6.0;
+
+pub fn setup_drone_scene(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Cuboid::new(1.0, 0.2, 1.0)),
+            material: materials.add(StandardMaterial {
+                base_color: Color::srgb(0.8, 0.2, 0.2),
+                ..default()
+            }),
+            ..default()
+        },
+        Drone,
+        DroneOrientation::default(),
+    ));
+
+    commands.spawn(PointLightBundle {
+        transform: Transform::from_xyz(4.0, 8.0, 4.0),
+        ..default()
+    });
+
+    commands.spawn((
+        Camera3dBundle {
+            transform: Transform::from_xyz(0.0, 2.4, 6.0).looking_at(Vec3::ZERO, Vec3::Y),
+            ..default()
+        },
+        FollowCamera::default(),
+    ));
+}
+
+/// Apply the latest attitude to the drone's own mesh transform, then
+/// smoothly move/orient the follow camera toward its target transform each
+/// frame rather than snapping, so attitude changes read naturally.
+pub fn camera_follow_system(
+    time: Res<Time>,
+    state: Res<AppState>,
+    mut drone_query: Query<(&mut Transform, &DroneOrientation), (With<Drone>, Without<FollowCamera>)>,
+    mut camera_query: Query<(&mut Transform, &FollowCamera)>,
+) {
+    let Ok((mut drone_transform, orientation)) = drone_query.get_single_mut() else {
+        return;
+    };
+
+    // The drone mesh itself must bank with its own attitude, not just the
+    // camera that tracks it.
+    drone_transform.rotation = orientation.rotation();
+    let drone_transform = &*drone_transform;
+
+    for (mut camera_transform, follow) in camera_query.iter_mut() {
+        let target = match state.camera_mode {
+            CameraMode::Chase => {
+                chase_target(drone_transform, orientation, state.camera_follow_distance)
+            }
+            CameraMode::Orbit => orbit_target(drone_transform, follow, state.camera_follow_distance),
+        };
+
+        let t = (time.delta_seconds() * FOLLOW_LERP_SPEED).min(1.0);
+        camera_transform.translation = camera_transform.translation.lerp(target.translation, t);
+        camera_transform.rotation = camera_transform.rotation.slerp(target.rotation, t);
+    }
+}
+
+fn chase_target(drone_transform: &Transform, orientation: &DroneOrientation, distance: f32) -> Transform {
+    let up = orientation.up();
+    let back = orientation.rotation() * Vec3::Z;
+    let position = drone_transform.translation + back * distance + up * (distance * 0.4);
+    Transform::from_translation(position).looking_at(drone_transform.translation, up)
+}
+
+fn orbit_target(drone_transform: &Transform, follow: &FollowCamera, distance: f32) -> Transform {
+    let rotation = Quat::from_euler(EulerRot::YXZ, follow.orbit_yaw, follow.orbit_pitch, 0.0);
+    let offset = rotation * (Vec3::Z * distance);
+    let position = drone_transform.translation + offset;
+    Transform::from_translation(position).looking_at(drone_transform.translation, Vec3::Y)
+}
+
+/// Rotate the orbit camera by dragging with the right mouse button, only
+/// while `CameraMode::Orbit` is active.
+pub fn orbit_mouse_system(
+    state: Res<AppState>,
+    mut motion: EventReader<bevy::input::mouse::MouseMotion>,
+    buttons: Res<ButtonInput<MouseButton>>,
+    mut camera_query: Query<&mut FollowCamera>,
+) {
+    if state.camera_mode != CameraMode::Orbit || !buttons.pressed(MouseButton::Right) {
+        motion.clear();
+        return;
+    }
+
+    let delta: Vec2 = motion.read().map(|event| event.delta).sum();
+    if delta == Vec2::ZERO {
+        return;
+    }
+
+    const ORBIT_SENSITIVITY: f32 = 0.005;
+    for mut follow in camera_query.iter_mut() {
+        follow.orbit_yaw -= delta.x * ORBIT_SENSITIVITY;
+        follow.orbit_pitch = (follow.orbit_pitch - delta.y * ORBIT_SENSITIVITY).clamp(-1.4, 1.4);
+    }
+}
@@ -0,0 +1,187 @@
+//! Gamepad to flight-command mapping.
+//!
+//! [`gamepad_input_system`] samples gilrs axis/button state once per frame,
+//! shapes the stick inputs with a deadzone + expo curve, and emits
+//! `UartCommand::Send` messages through whichever transport is currently
+//! selected, rate-limited so the link isn't flooded. Button presses arm or
+//! disarm and step the selected `PidAxis` gains.
+
+use bevy::prelude::*;
+use gilrs::{Axis, Button};
+
+use crate::app::{AppState, GamepadState};
+use crate::telemetry::PidAxis;
+use crate::uart::UartCommand;
+
+/// Fixed rate at which stick commands are transmitted, in Hz.
+const SEND_RATE_HZ: f32 = 20.0;
+const SEND_PERIOD: f32 = 1.0 / SEND_RATE_HZ;
+
+/// Axis choices offered in the Controls panel.
+pub const AXIS_OPTIONS: [Axis; 6] = [
+    Axis::LeftStickX,
+    Axis::LeftStickY,
+    Axis::RightStickX,
+    Axis::RightStickY,
+    Axis::LeftZ,
+    Axis::RightZ,
+];
+
+/// Button choices offered in the Controls panel.
+pub const BUTTON_OPTIONS: [Button; 8] = [
+    Button::South,
+    Button::East,
+    Button::North,
+    Button::West,
+    Button::DPadUp,
+    Button::DPadDown,
+    Button::DPadLeft,
+    Button::DPadRight,
+];
+
+/// A stick axis binding with a deadzone and expo curve applied before the
+/// value is sent over the link.
+#[derive(Debug, Clone, Copy)]
+pub struct AxisBinding {
+    pub axis: Axis,
+    pub deadzone: f32,
+    pub expo: f32,
+}
+
+impl AxisBinding {
+    fn new(axis: Axis) -> Self {
+        Self {
+            axis,
+            deadzone: 0.1,
+            expo: 0.3,
+        }
+    }
+
+    /// Apply the deadzone and expo curve to a raw `-1.0..=1.0` stick value.
+    fn shape(&self, raw: f32) -> f32 {
+        let magnitude = raw.abs();
+        if magnitude < self.deadzone {
+            return 0.0;
+        }
+        let normalized = ((magnitude - self.deadzone) / (1.0 - self.deadzone)).clamp(0.0, 1.0);
+        let shaped = self.expo * normalized.powi(3) + (1.0 - self.expo) * normalized;
+        shaped.copysign(raw)
+    }
+}
+
+/// Axis/button bindings and shaping parameters for the connected gamepad,
+/// rebindable from the Controls panel without recompiling.
+#[derive(Debug, Clone, Copy)]
+pub struct GamepadBindings {
+    pub throttle: AxisBinding,
+    pub yaw: AxisBinding,
+    pub roll: AxisBinding,
+    pub pitch: AxisBinding,
+    pub arm: Button,
+    pub disarm: Button,
+    pub next_pid_axis: Button,
+    pub gain_increase: Button,
+    pub gain_decrease: Button,
+}
+
+impl Default for GamepadBindings {
+    fn default() -> Self {
+        Self {
+            throttle: AxisBinding::new(Axis::LeftStickY),
+            yaw: AxisBinding::new(Axis::LeftStickX),
+            roll: AxisBinding::new(Axis::RightStickX),
+            pitch: AxisBinding::new(Axis::RightStickY),
+            arm: Button::South,
+            disarm: Button::East,
+            next_pid_axis: Button::North,
+            gain_increase: Button::DPadUp,
+            gain_decrease: Button::DPadDown,
+        }
+    }
+}
+
+/// Sample the connected gamepad and forward shaped stick/button input as
+/// `UartCommand::Send` messages, rate-limited to [`SEND_RATE_HZ`]. Button
+/// bindings fire once per press (via gilrs's `ButtonPressed` events) rather
+/// than once per frame the button is held.
+pub fn gamepad_input_system(
+    time: Res<Time>,
+    gamepad: Option<NonSendMut<GamepadState>>,
+    mut state: ResMut<AppState>,
+) {
+    let Some(mut gamepad) = gamepad else {
+        return;
+    };
+
+    let bindings = state.gamepad_bindings;
+    let mut presses = Vec::new();
+    while let Some(gilrs::Event { event, .. }) = gamepad.gilrs.next_event() {
+        if let gilrs::EventType::ButtonPressed(button, _) = event {
+            presses.push(button);
+        }
+    }
+
+    for button in presses {
+        if button == bindings.arm {
+            state.armed = true;
+            state.send_arm_command(true);
+        } else if button == bindings.disarm {
+            state.armed = false;
+            state.send_arm_command(false);
+        } else if button == bindings.next_pid_axis {
+            state.selected_pid_axis = next_axis(state.selected_pid_axis);
+        } else if button == bindings.gain_increase && state.armed {
+            let data = format!("PID:{}:+", axis_code(state.selected_pid_axis));
+            send_command(&state, data);
+        } else if button == bindings.gain_decrease && state.armed {
+            let data = format!("PID:{}:-", axis_code(state.selected_pid_axis));
+            send_command(&state, data);
+        }
+    }
+
+    let Some((_, gp)) = gamepad.gilrs.gamepads().next() else {
+        return;
+    };
+
+    state.gamepad_send_timer += time.delta_seconds();
+    if state.gamepad_send_timer < SEND_PERIOD {
+        return;
+    }
+    state.gamepad_send_timer = 0.0;
+
+    if !state.armed {
+        return;
+    }
+
+    let throttle = bindings.throttle.shape(gp.value(bindings.throttle.axis));
+    let yaw = bindings.yaw.shape(gp.value(bindings.yaw.axis));
+    let roll = bindings.roll.shape(gp.value(bindings.roll.axis));
+    let pitch = bindings.pitch.shape(gp.value(bindings.pitch.axis));
+
+    let data = format!("STICK:{throttle:.3}:{yaw:.3}:{roll:.3}:{pitch:.3}");
+    send_command(&state, data);
+}
+
+fn next_axis(axis: PidAxis) -> PidAxis {
+    match axis {
+        PidAxis::Roll => PidAxis::Pitch,
+        PidAxis::Pitch => PidAxis::Yaw,
+        PidAxis::Yaw => PidAxis::Roll,
+    }
+}
+
+fn axis_code(axis: PidAxis) -> &'static str {
+    match axis {
+        PidAxis::Roll => "ROLL",
+        PidAxis::Pitch => "PITCH",
+        PidAxis::Yaw => "YAW",
+    }
+}
+
+fn send_command(state: &AppState, data: String) {
+    if let Some(sender) = state.active_sender() {
+        if let Err(e) = sender.send(UartCommand::Send { address: 0, data }) {
+            eprintln!("Failed to send gamepad command: {e}");
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::io::ErrorKind;
+use std::net::UdpSocket;
+use std::sync::{Arc, Mutex, mpsc};
+use std::thread;
+use std::time::Duration;
+
+use crate::parser::handle_line;
+use crate::telemetry::DataBuffer;
+use crate::uart::UartCommand;
+
+/// Spawn the background thread that owns a UDP socket connected to
+/// `remote_addr` with a 100 ms read timeout, structured like `uart::start_uart_thread`: it reads
+/// datagrams, runs them through the same
+/// `parse_rcv`/`parse_telemetry`/`parse_log` pipeline, and pushes results
+/// into the shared `DataBuffer`. The returned sender mirrors
+/// `UartCommand::Send` so the "Send" button and gamepad bindings can target
+/// either transport; dropping it shuts the thread down cleanly.
+pub fn start_net_thread(
+    remote_addr: String,
+    data_buffer: Arc<Mutex<DataBuffer>>,
+) -> std::io::Result<mpsc::Sender<UartCommand>> {
+    let socket = UdpSocket::bind("0.0.0.0:0")?;
+    socket.set_read_timeout(Some(Duration::from_millis(100)))?;
+    socket.connect(&remote_addr)?;
+
+    let (tx, rx) = mpsc::channel::<UartCommand>();
+
+    thread::spawn(move || {
+        let mut buf = [0u8; 1500];
+
+        'outer: loop {
+            loop {
+                match rx.try_recv() {
+                    Ok(UartCommand::Send { address, data }) => {
+                        let frame = format!("AT+SEND={address},{},{data}\r\n", data.len());
+                        if let Err(e) = socket.send(frame.as_bytes()) {
+                            eprintln!("Failed to send UDP datagram to {remote_addr}: {e}");
+                        }
+                    }
+                    Err(mpsc::TryRecvError::Empty) => break,
+                    // Sender dropped (GUI disconnected the transport): exit.
+                    Err(mpsc::TryRecvError::Disconnected) => break 'outer,
+                }
+            }
+
+            match socket.recv(&mut buf) {
+                Ok(n) => {
+                    let line = String::from_utf8_lossy(&buf[..n]).trim().to_string();
+                    if !line.is_empty() {
+                        handle_line(&line, &data_buffer);
+                    }
+                }
+                Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+                Err(e) => {
+                    eprintln!("UDP transport to {remote_addr} failed: {e}");
+                    break;
+                }
+            }
+        }
+    });
+
+    Ok(tx)
+}
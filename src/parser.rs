@@ -1,7 +1,9 @@
+use std::sync::{Arc, Mutex};
+
 use chrono::Local;
 
-use crate::protocol::TelemetryPacket;
-use crate::telemetry::{ReceivedMessage, TelemetryData};
+use crate::protocol::{TelemetryPacket, TELEMETRY_PACKET_BYTES};
+use crate::telemetry::{DataBuffer, ReceivedMessage, TelemetryData};
 
 pub fn parse_rcv(line: &str) -> Option<ReceivedMessage> {
     let parts: Vec<&str> = line.strip_prefix("+RCV=")?.split(",").collect();
@@ -23,8 +25,9 @@ pub fn parse_rcv(line: &str) -> Option<ReceivedMessage> {
 }
 
 /// Parse telemetry from serial data
-/// Format: "TELEM:roll:pitch:yaw:roll_p:roll_i:roll_d:pitch_p:pitch_i:pitch_d:yaw_p:yaw_i:yaw_d:alt:voltage"
-/// Each field is a float formatted as [sign]whole.decimal (e.g., "0.123", "-1.456")
+/// Format: "T:<hex>", an ASCII hex string decoding to a packed little-endian
+/// [`TelemetryPacket`] (56 bytes / 14 floats), optionally followed by one
+/// trailing XOR checksum byte.
 pub fn parse_telemetry(line: &str) -> Option<TelemetryData> {
     let mut parts = line.splitn(2, ':');
     let header = parts.next()?;
@@ -34,7 +37,39 @@ pub fn parse_telemetry(line: &str) -> Option<TelemetryData> {
         return None;
     }
 
-    todo!()
+    let bytes = decode_hex(hex)?;
+
+    let payload = match bytes.len() {
+        n if n == TELEMETRY_PACKET_BYTES => &bytes[..],
+        n if n == TELEMETRY_PACKET_BYTES + 1 => {
+            let (payload, checksum) = bytes.split_at(TELEMETRY_PACKET_BYTES);
+            if xor_checksum(payload) != checksum[0] {
+                return None;
+            }
+            payload
+        }
+        _ => return None,
+    };
+
+    let packet = TelemetryPacket::from_bytes(payload)?;
+    Some(TelemetryData::from(packet))
+}
+
+/// Decode an ASCII hex string into bytes, rejecting odd length or non-hex
+/// characters instead of panicking.
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    hex.as_bytes()
+        .chunks_exact(2)
+        .map(|pair| u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok())
+        .collect()
+}
+
+fn xor_checksum(payload: &[u8]) -> u8 {
+    payload.iter().fold(0u8, |acc, b| acc ^ b)
 }
 
 /// Parse log message from serial data
@@ -42,3 +77,23 @@ pub fn parse_telemetry(line: &str) -> Option<TelemetryData> {
 pub fn parse_log(line: &str) -> Option<String> {
     line.strip_prefix("LOG:").map(str::to_string)
 }
+
+/// Run a line received from either transport through `parse_telemetry` /
+/// `parse_rcv` / `parse_log` and push whichever of them matches into
+/// `data_buffer`. Shared by `uart::start_uart_thread` and
+/// `net::start_net_thread` so the two transports can't drift apart.
+pub fn handle_line(line: &str, data_buffer: &Arc<Mutex<DataBuffer>>) {
+    if let Some(telemetry) = parse_telemetry(line) {
+        if let Ok(mut buffer) = data_buffer.lock() {
+            buffer.push_telemetry(telemetry);
+        }
+    } else if let Some(message) = parse_rcv(line) {
+        if let Ok(mut buffer) = data_buffer.lock() {
+            buffer.push_received(message);
+        }
+    } else if let Some(log) = parse_log(line) {
+        if let Ok(mut buffer) = data_buffer.lock() {
+            buffer.push_log(log);
+        }
+    }
+}
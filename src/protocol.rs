@@ -0,0 +1,68 @@
+//! Binary wire format shared between the flight controller firmware and the
+//! ground station.
+//!
+//! Telemetry is framed as `"T:<hex>"` over the LoRa link (see
+//! [`crate::parser::parse_telemetry`]); this module defines the packed
+//! little-endian layout that hex payload decodes to.
+
+/// Number of `f32` fields packed into a [`TelemetryPacket`].
+pub const TELEMETRY_PACKET_FLOATS: usize = 14;
+
+/// Size in bytes of a [`TelemetryPacket`] payload (without the optional
+/// trailing checksum byte).
+pub const TELEMETRY_PACKET_BYTES: usize = TELEMETRY_PACKET_FLOATS * 4;
+
+/// Fixed little-endian layout decoded from a hex-framed telemetry packet.
+///
+/// Field order on the wire: roll, pitch, yaw, then the nine PID gains
+/// (roll p/i/d, pitch p/i/d, yaw p/i/d), then altitude and battery voltage.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TelemetryPacket {
+    pub roll: f32,
+    pub pitch: f32,
+    pub yaw: f32,
+    pub roll_p: f32,
+    pub roll_i: f32,
+    pub roll_d: f32,
+    pub pitch_p: f32,
+    pub pitch_i: f32,
+    pub pitch_d: f32,
+    pub yaw_p: f32,
+    pub yaw_i: f32,
+    pub yaw_d: f32,
+    pub altitude: f32,
+    pub battery_voltage: f32,
+}
+
+impl TelemetryPacket {
+    /// Decode a packet from exactly [`TELEMETRY_PACKET_BYTES`] bytes.
+    ///
+    /// Returns `None` if `bytes` isn't the expected length.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != TELEMETRY_PACKET_BYTES {
+            return None;
+        }
+
+        let mut floats = [0f32; TELEMETRY_PACKET_FLOATS];
+        for (i, chunk) in bytes.chunks_exact(4).enumerate() {
+            floats[i] = f32::from_le_bytes(chunk.try_into().ok()?);
+        }
+
+        Some(Self {
+            roll: floats[0],
+            pitch: floats[1],
+            yaw: floats[2],
+            roll_p: floats[3],
+            roll_i: floats[4],
+            roll_d: floats[5],
+            pitch_p: floats[6],
+            pitch_i: floats[7],
+            pitch_d: floats[8],
+            yaw_p: floats[9],
+            yaw_i: floats[10],
+            yaw_d: floats[11],
+            altitude: floats[12],
+            battery_voltage: floats[13],
+        })
+    }
+}
@@ -0,0 +1,161 @@
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Local};
+use egui_plot::PlotPoints;
+
+use crate::protocol::TelemetryPacket;
+
+/// Number of samples kept around for the plots before old ones are dropped.
+const HISTORY_LEN: usize = 512;
+
+/// Which axis' PID gains the "PID Selection" plot is currently showing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PidAxis {
+    #[default]
+    Roll,
+    Pitch,
+    Yaw,
+}
+
+/// A single decoded telemetry sample, timestamped on arrival.
+#[derive(Debug, Clone)]
+pub struct TelemetryData {
+    pub roll: f32,
+    pub pitch: f32,
+    pub yaw: f32,
+    pub roll_p: f32,
+    pub roll_i: f32,
+    pub roll_d: f32,
+    pub pitch_p: f32,
+    pub pitch_i: f32,
+    pub pitch_d: f32,
+    pub yaw_p: f32,
+    pub yaw_i: f32,
+    pub yaw_d: f32,
+    pub altitude: f32,
+    pub battery_voltage: f32,
+    pub time: DateTime<Local>,
+}
+
+impl From<TelemetryPacket> for TelemetryData {
+    fn from(packet: TelemetryPacket) -> Self {
+        Self {
+            roll: packet.roll,
+            pitch: packet.pitch,
+            yaw: packet.yaw,
+            roll_p: packet.roll_p,
+            roll_i: packet.roll_i,
+            roll_d: packet.roll_d,
+            pitch_p: packet.pitch_p,
+            pitch_i: packet.pitch_i,
+            pitch_d: packet.pitch_d,
+            yaw_p: packet.yaw_p,
+            yaw_i: packet.yaw_i,
+            yaw_d: packet.yaw_d,
+            altitude: packet.altitude,
+            battery_voltage: packet.battery_voltage,
+            time: Local::now(),
+        }
+    }
+}
+
+/// A LoRa `+RCV=` reply: an addressed message with link-quality metadata.
+#[derive(Debug, Clone)]
+pub struct ReceivedMessage {
+    pub from: u32,
+    pub length: u32,
+    pub message: String,
+    pub rssi: i32,
+    pub snr: i32,
+    pub time: DateTime<Local>,
+}
+
+/// A free-form log line surfaced from the drone over the `LOG:` prefix.
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub clock_time: DateTime<Local>,
+    pub message: String,
+}
+
+/// Rolling history of everything the ground station has received, backing
+/// the plots and log view in [`crate::app::ui_system`].
+#[derive(Debug, Default)]
+pub struct DataBuffer {
+    pub data: VecDeque<TelemetryData>,
+    pub logs: VecDeque<LogEntry>,
+    pub received: VecDeque<ReceivedMessage>,
+}
+
+impl DataBuffer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push_telemetry(&mut self, data: TelemetryData) {
+        self.data.push_back(data);
+        while self.data.len() > HISTORY_LEN {
+            self.data.pop_front();
+        }
+    }
+
+    pub fn push_log(&mut self, message: String) {
+        self.logs.push_back(LogEntry {
+            clock_time: Local::now(),
+            message,
+        });
+        while self.logs.len() > HISTORY_LEN {
+            self.logs.pop_front();
+        }
+    }
+
+    pub fn push_received(&mut self, message: ReceivedMessage) {
+        self.received.push_back(message);
+        while self.received.len() > HISTORY_LEN {
+            self.received.pop_front();
+        }
+    }
+
+    fn series(&self, pick: impl Fn(&TelemetryData) -> f32) -> PlotPoints {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(i, sample)| [i as f64, pick(sample) as f64])
+            .collect()
+    }
+
+    pub fn get_roll_data(&self) -> PlotPoints {
+        self.series(|d| d.roll)
+    }
+
+    pub fn get_pitch_data(&self) -> PlotPoints {
+        self.series(|d| d.pitch)
+    }
+
+    pub fn get_yaw_data(&self) -> PlotPoints {
+        self.series(|d| d.yaw)
+    }
+
+    pub fn get_pid_p_data(&self, axis: PidAxis) -> PlotPoints {
+        match axis {
+            PidAxis::Roll => self.series(|d| d.roll_p),
+            PidAxis::Pitch => self.series(|d| d.pitch_p),
+            PidAxis::Yaw => self.series(|d| d.yaw_p),
+        }
+    }
+
+    pub fn get_pid_i_data(&self, axis: PidAxis) -> PlotPoints {
+        match axis {
+            PidAxis::Roll => self.series(|d| d.roll_i),
+            PidAxis::Pitch => self.series(|d| d.pitch_i),
+            PidAxis::Yaw => self.series(|d| d.yaw_i),
+        }
+    }
+
+    pub fn get_pid_d_data(&self, axis: PidAxis) -> PlotPoints {
+        match axis {
+            PidAxis::Roll => self.series(|d| d.roll_d),
+            PidAxis::Pitch => self.series(|d| d.pitch_d),
+            PidAxis::Yaw => self.series(|d| d.yaw_d),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use std::io::{Read, Write};
+use std::sync::{Arc, Mutex, mpsc};
+use std::thread;
+use std::time::Duration;
+
+use crate::parser::handle_line;
+use crate::telemetry::DataBuffer;
+
+/// Commands the UI (or a gamepad binding) can push down to the UART worker
+/// thread.
+pub enum UartCommand {
+    Send { address: u16, data: String },
+}
+
+/// Spawn the background thread that owns the serial port: it forwards parsed
+/// telemetry/log/`+RCV=` lines into `data_buffer` and drains `UartCommand`s
+/// sent back from the UI.
+pub fn start_uart_thread(
+    port_path: String,
+    data_buffer: Arc<Mutex<DataBuffer>>,
+) -> mpsc::Sender<UartCommand> {
+    let (tx, rx) = mpsc::channel::<UartCommand>();
+
+    thread::spawn(move || {
+        let mut port = match serialport::new(&port_path, 57_600)
+            .timeout(Duration::from_millis(100))
+            .open()
+        {
+            Ok(port) => port,
+            Err(e) => {
+                if let Ok(mut buffer) = data_buffer.lock() {
+                    buffer.push_log(format!("Failed to open {port_path}: {e}"));
+                }
+                return;
+            }
+        };
+
+        let mut line_buf = Vec::new();
+        let mut byte = [0u8; 1];
+
+        loop {
+            while let Ok(cmd) = rx.try_recv() {
+                match cmd {
+                    UartCommand::Send { address, data } => {
+                        let frame = format!("AT+SEND={address},{},{data}\r\n", data.len());
+                        if let Err(e) = port.write_all(frame.as_bytes()) {
+                            eprintln!("Failed to write to {port_path}: {e}");
+                        }
+                    }
+                }
+            }
+
+            match port.read(&mut byte) {
+                Ok(1) if byte[0] == b'\n' => {
+                    let line = String::from_utf8_lossy(&line_buf).trim().to_string();
+                    line_buf.clear();
+                    if !line.is_empty() {
+                        handle_line(&line, &data_buffer);
+                    }
+                }
+                Ok(1) if byte[0] != b'\r' => line_buf.push(byte[0]),
+                _ => {}
+            }
+        }
+    });
+
+    tx
+}
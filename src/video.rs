@@ -0,0 +1,252 @@
+//! Video capture from a V4L2 device into a shared frame buffer the UI polls
+//! each frame to update its egui texture, plus an optional PipeWire output
+//! that mirrors the same frames onto the graph for external capture.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, mpsc};
+use std::thread;
+use std::time::Duration;
+
+use pipewire as pw;
+use pw::properties::properties;
+use pw::spa::param::format::{FormatProperties, MediaSubtype, MediaType};
+use pw::spa::param::video::VideoFormat;
+use pw::spa::pod::serialize::PodSerializer;
+use pw::spa::pod::{Pod, Value, property};
+use pw::spa::utils::{Direction, Rectangle, SpaTypes};
+use pw::stream::{Stream, StreamFlags};
+use v4l::buffer::Type;
+use v4l::io::traits::CaptureStream;
+use v4l::prelude::*;
+use v4l::video::Capture;
+
+/// A single captured RGB frame, ready to hand to `egui::ColorImage::from_rgb`
+/// or to publish onto a PipeWire stream.
+#[derive(Debug, Clone)]
+pub struct VideoFrame {
+    pub width: usize,
+    pub height: usize,
+    pub data: Vec<u8>,
+}
+
+/// Frame buffer shared between the capture thread and the UI.
+pub type SharedVideoFrame = Arc<Mutex<Option<VideoFrame>>>;
+
+/// Handle to a running capture thread, so the UI has a real disconnect path
+/// (which also tears down any PipeWire output mirroring these frames).
+#[derive(Clone)]
+pub struct VideoHandle {
+    running: Arc<AtomicBool>,
+}
+
+impl VideoHandle {
+    /// Signal the capture thread to stop after its current frame.
+    pub fn stop(&self) {
+        self.running.store(false, Ordering::Relaxed);
+    }
+}
+
+/// Spawn the background thread that owns the V4L2 device, decoding each
+/// captured buffer to RGB and publishing it into the returned frame slot.
+pub fn start_video_thread(device_path: &str) -> Result<(SharedVideoFrame, VideoHandle), String> {
+    let mut device = Device::with_path(device_path).map_err(|e| e.to_string())?;
+    let format = device.format().map_err(|e| e.to_string())?;
+    let width = format.width as usize;
+    let height = format.height as usize;
+
+    let mut stream =
+        MmapStream::with_buffers(&mut device, Type::VideoCapture, 4).map_err(|e| e.to_string())?;
+
+    let frame_buffer: SharedVideoFrame = Arc::new(Mutex::new(None));
+    let shared = Arc::clone(&frame_buffer);
+
+    let running = Arc::new(AtomicBool::new(true));
+    let running_thread = Arc::clone(&running);
+
+    thread::spawn(move || {
+        while running_thread.load(Ordering::Relaxed) {
+            let (buf, _meta) = match stream.next() {
+                Ok(frame) => frame,
+                Err(_) => break,
+            };
+            let data = yuyv_to_rgb(buf, width, height);
+            if let Ok(mut slot) = shared.lock() {
+                *slot = Some(VideoFrame {
+                    width,
+                    height,
+                    data,
+                });
+            }
+        }
+    });
+
+    Ok((frame_buffer, VideoHandle { running }))
+}
+
+/// Convert a YUYV (YUV 4:2:2) buffer, the common V4L2 default, to packed RGB.
+fn yuyv_to_rgb(buf: &[u8], width: usize, height: usize) -> Vec<u8> {
+    let mut rgb = Vec::with_capacity(width * height * 3);
+    for chunk in buf.chunks_exact(4) {
+        let y0 = chunk[0] as f32;
+        let u = chunk[1] as f32 - 128.0;
+        let y1 = chunk[2] as f32;
+        let v = chunk[3] as f32 - 128.0;
+        for y in [y0, y1] {
+            rgb.push((y + 1.402 * v).clamp(0.0, 255.0) as u8);
+            rgb.push((y - 0.344 * u - 0.714 * v).clamp(0.0, 255.0) as u8);
+            rgb.push((y + 1.772 * u).clamp(0.0, 255.0) as u8);
+        }
+    }
+    rgb
+}
+
+enum PipewireMsg {
+    Stop,
+}
+
+struct StreamUserData {
+    frame_buffer: SharedVideoFrame,
+}
+
+/// How long `start` waits for `run_pipewire_loop` to finish connecting the
+/// stream before giving up and reporting failure.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Handle to a running PipeWire video source node. Dropping it tears the
+/// node down and stops the background main loop.
+pub struct PipewireOutput {
+    sender: pw::channel::Sender<PipewireMsg>,
+}
+
+impl PipewireOutput {
+    /// Register a "drone-camera" source node negotiated for `width`x`height`
+    /// RGB frames, and start mirroring `frame_buffer` onto it. Blocks until
+    /// the background thread has connected the stream and offered that
+    /// format (or failed to), so the caller never has to assume success;
+    /// format negotiation itself completes later on the main loop.
+    pub fn start(frame_buffer: SharedVideoFrame, width: usize, height: usize) -> Result<Self, String> {
+        let (sender, receiver) = pw::channel::channel::<PipewireMsg>();
+        let (ready_tx, ready_rx) = mpsc::channel::<Result<(), String>>();
+
+        thread::spawn(move || {
+            if let Err(e) = run_pipewire_loop(frame_buffer, width, height, receiver, ready_tx) {
+                eprintln!("PipeWire output stopped: {e}");
+            }
+        });
+
+        match ready_rx.recv_timeout(CONNECT_TIMEOUT) {
+            Ok(Ok(())) => Ok(Self { sender }),
+            Ok(Err(e)) => Err(e),
+            Err(_) => Err("Timed out waiting for PipeWire to connect".to_string()),
+        }
+    }
+}
+
+impl Drop for PipewireOutput {
+    fn drop(&mut self) {
+        let _ = self.sender.send(PipewireMsg::Stop);
+    }
+}
+
+/// Connect to PipeWire and register the stream, reporting the outcome
+/// through `ready` before running the main loop (which only happens on
+/// success).
+fn run_pipewire_loop(
+    frame_buffer: SharedVideoFrame,
+    width: usize,
+    height: usize,
+    receiver: pw::channel::Receiver<PipewireMsg>,
+    ready: mpsc::Sender<Result<(), String>>,
+) -> Result<(), pw::Error> {
+    let setup = || -> Result<_, pw::Error> {
+        pw::init();
+
+        let mainloop = pw::main_loop::MainLoop::new(None)?;
+        let context = pw::context::Context::new(&mainloop)?;
+        let core = context.connect(None)?;
+
+        let stream = Stream::new(
+            &core,
+            "drone-camera",
+            properties! {
+                *pw::keys::MEDIA_TYPE => "Video",
+                *pw::keys::MEDIA_CATEGORY => "Source",
+                *pw::keys::MEDIA_ROLE => "Camera",
+            },
+        )?;
+
+        let listener = stream
+            .add_local_listener_with_user_data(StreamUserData { frame_buffer })
+            .process(|stream, user_data| {
+                let Some(mut buffer) = stream.dequeue_buffer() else {
+                    return;
+                };
+                let Some(frame) = user_data.frame_buffer.lock().ok().and_then(|g| g.clone()) else {
+                    return;
+                };
+                if let Some(plane) = buffer.datas_mut().first_mut() {
+                    if let Some(dest) = plane.data() {
+                        let len = dest.len().min(frame.data.len());
+                        dest[..len].copy_from_slice(&frame.data[..len]);
+                        *plane.chunk_mut().size_mut() = len as u32;
+                    }
+                }
+            })
+            .register()?;
+
+        let format_pod = format_params(width, height)?;
+        let mut params = [Pod::from_bytes(&format_pod).ok_or(pw::Error::CreationFailed)?];
+
+        stream.connect(
+            Direction::Output,
+            None,
+            StreamFlags::MAP_BUFFERS | StreamFlags::DRIVER,
+            &mut params,
+        )?;
+
+        Ok((mainloop, listener))
+    };
+
+    let (mainloop, _listener) = match setup() {
+        Ok(ready_state) => ready_state,
+        Err(e) => {
+            let _ = ready.send(Err(e.to_string()));
+            return Err(e);
+        }
+    };
+
+    let _receiver_guard = receiver.attach(mainloop.loop_(), {
+        let mainloop = mainloop.clone();
+        move |msg| match msg {
+            PipewireMsg::Stop => mainloop.quit(),
+        }
+    });
+
+    let _ = ready.send(Ok(()));
+    mainloop.run();
+    Ok(())
+}
+
+/// Build the EnumFormat pod negotiating a raw RGB stream at `width`x`height`,
+/// taken from the captured frame buffer's actual dimensions.
+fn format_params(width: usize, height: usize) -> Result<Vec<u8>, pw::Error> {
+    let object = pw::spa::pod::object!(
+        SpaTypes::ObjectParamFormat,
+        pw::spa::param::ParamType::EnumFormat,
+        property!(FormatProperties::MediaType, Id, MediaType::Video),
+        property!(FormatProperties::MediaSubtype, Id, MediaSubtype::Raw),
+        property!(FormatProperties::VideoFormat, Id, VideoFormat::RGB),
+        property!(
+            FormatProperties::VideoSize,
+            Rectangle,
+            Rectangle {
+                width: width as u32,
+                height: height as u32,
+            }
+        ),
+    );
+
+    PodSerializer::serialize(std::io::Cursor::new(Vec::new()), &Value::Object(object))
+        .map(|(cursor, _)| cursor.into_inner())
+        .map_err(|_| pw::Error::CreationFailed)
+}